@@ -1,53 +1,327 @@
 import { invoke } from '@tauri-apps/api/core'
-import type { GitStatus } from '@/types'
+import { listen, type UnlistenFn } from '@tauri-apps/api/event'
+import type { BranchInfo, CommitDetail, ConflictEntry, ConflictResolution, ConflictVersions, DiffResult, FetchResult, GitStatus, HunkSelection, LogOptions, LogPage, RemoteInfo, SshPassphraseRequest, StashEntry, TagInfo } from '@/types'
 
 export const gitApi = {
     /**
-     * 获取 Git 仓库状态
+     * 获取 Git 仓库状态，includeIgnored 为 true 时 files 中同时列出被忽略的文件
      */
-    async getStatus(path: string): Promise<GitStatus> {
-        return invoke<GitStatus>('get_repository_status', { path })
+    async getStatus(path: string, includeIgnored?: boolean): Promise<GitStatus> {
+        return invoke<GitStatus>('get_repository_status', { path, includeIgnored })
     },
 
     /**
-     * Git 拉取
+     * Git 拉取（失败时抛出 GitError）
      */
-    async pull(path: string): Promise<string> {
-        return invoke<string>('git_pull', { path })
+    async pull(path: string, operationId?: string): Promise<string> {
+        return invoke<string>('git_pull', { path, operationId })
     },
 
     /**
      * Git 推送
      */
-    async push(path: string): Promise<string> {
-        return invoke<string>('git_push', { path })
+    async push(path: string, operationId?: string): Promise<string> {
+        return invoke<string>('git_push', { path, operationId })
     },
 
     /**
      * Git 提交
+     * @param options.stagedOnly 仅提交暂存区中的内容
+     * @param options.includeUntracked 同时提交未跟踪的文件，不能与 stagedOnly 同时使用
      */
-    async commit(path: string, message: string): Promise<string> {
-        return invoke<string>('git_commit', { path, message })
+    async commit(
+        path: string,
+        message: string,
+        options: { stagedOnly?: boolean; includeUntracked?: boolean } = {},
+    ): Promise<string> {
+        return invoke<string>('git_commit', { path, message, ...options })
+    },
+
+    /**
+     * 暂存文件
+     */
+    async stage(path: string, files: string[]): Promise<string> {
+        return invoke<string>('git_stage', { path, files })
+    },
+
+    /**
+     * 取消暂存文件
+     */
+    async unstage(path: string, files: string[]): Promise<string> {
+        return invoke<string>('git_unstage', { path, files })
     },
 
     /**
      * Git Diff
      */
-    async diff(path: string): Promise<string> {
-        return invoke<string>('git_diff', { path })
+    async diff(path: string): Promise<DiffResult> {
+        return invoke<DiffResult>('git_diff', { path })
+    },
+
+    /**
+     * 获取未暂存的差异（按 hunk 组织）
+     */
+    async diffHunks(path: string, files?: string[]): Promise<DiffResult> {
+        return invoke<DiffResult>('git_diff_hunks', { path, files })
+    },
+
+    /**
+     * 暂存选中的 hunk 或行
+     */
+    async stageHunks(path: string, selections: HunkSelection[]): Promise<string> {
+        return invoke<string>('git_stage_hunks', { path, selections })
+    },
+
+    /**
+     * 列出冲突文件
+     */
+    async listConflicts(path: string): Promise<ConflictEntry[]> {
+        return invoke<ConflictEntry[]>('git_list_conflicts', { path })
+    },
+
+    /**
+     * 获取冲突文件的 ancestor / ours / theirs 三个版本
+     */
+    async getConflictVersions(path: string, file: string): Promise<ConflictVersions> {
+        return invoke<ConflictVersions>('git_get_conflict_versions', { path, file })
+    },
+
+    /**
+     * 解决冲突并标记为已解决
+     */
+    async resolveConflict(path: string, file: string, resolution: ConflictResolution): Promise<string> {
+        return invoke<string>('git_resolve_conflict', { path, file, resolution })
+    },
+
+    /**
+     * 中止合并，只恢复合并涉及的文件，其他本地修改保持不变
+     */
+    async abortMerge(path: string): Promise<string> {
+        return invoke<string>('git_abort_merge', { path })
+    },
+
+    /**
+     * 完成合并（失败时抛出 GitError）
+     */
+    async continueMerge(path: string, message?: string): Promise<string> {
+        return invoke<string>('git_continue_merge', { path, message })
+    },
+
+    /**
+     * 列出本地和远程分支
+     */
+    async listBranches(path: string): Promise<BranchInfo[]> {
+        return invoke<BranchInfo[]>('git_list_branches', { path })
+    },
+
+    /**
+     * 切换分支（远程分支会自动创建本地跟踪分支）
+     */
+    async checkoutBranch(path: string, name: string): Promise<string> {
+        return invoke<string>('git_checkout_branch', { path, name })
+    },
+
+    /**
+     * 创建分支
+     */
+    async createBranch(path: string, name: string, startPoint?: string, checkout = false): Promise<string> {
+        return invoke<string>('git_create_branch', { path, name, startPoint, checkout })
+    },
+
+    /**
+     * 删除本地分支（未合并的分支需要 force）
+     */
+    async deleteBranch(path: string, name: string, force = false): Promise<string> {
+        return invoke<string>('git_delete_branch', { path, name, force })
+    },
+
+    /**
+     * 重命名本地分支
+     */
+    async renameBranch(path: string, oldName: string, newName: string): Promise<string> {
+        return invoke<string>('git_rename_branch', { path, oldName, newName })
+    },
+
+    /**
+     * 设置上游分支，upstream 为空时取消跟踪
+     */
+    async setUpstream(path: string, name: string, upstream: string | null): Promise<string> {
+        return invoke<string>('git_set_upstream', { path, name, upstream })
+    },
+
+    /**
+     * 分页获取提交历史
+     */
+    async log(path: string, options?: LogOptions): Promise<LogPage> {
+        return invoke<LogPage>('git_log', { path, options })
+    },
+
+    /**
+     * 获取单个提交的详情和差异
+     */
+    async showCommit(path: string, oid: string): Promise<CommitDetail> {
+        return invoke<CommitDetail>('git_show_commit', { path, oid })
+    },
+
+    /**
+     * 贮藏当前更改
+     */
+    async stashSave(
+        path: string,
+        options: { message?: string; includeUntracked?: boolean; keepIndex?: boolean } = {},
+    ): Promise<string> {
+        return invoke<string>('git_stash_save', { path, ...options })
+    },
+
+    /**
+     * 列出贮藏
+     */
+    async stashList(path: string): Promise<StashEntry[]> {
+        return invoke<StashEntry[]>('git_stash_list', { path })
+    },
+
+    /**
+     * 应用贮藏（失败时抛出 GitError）
+     */
+    async stashApply(path: string, index: number): Promise<string> {
+        return invoke<string>('git_stash_apply', { path, index })
+    },
+
+    /**
+     * 应用并删除贮藏，出现冲突时保留贮藏（失败时抛出 GitError）
+     */
+    async stashPop(path: string, index: number): Promise<string> {
+        return invoke<string>('git_stash_pop', { path, index })
+    },
+
+    /**
+     * 删除贮藏
+     */
+    async stashDrop(path: string, index: number): Promise<string> {
+        return invoke<string>('git_stash_drop', { path, index })
+    },
+
+    /**
+     * 获取贮藏的差异
+     */
+    async stashShow(path: string, index: number): Promise<DiffResult> {
+        return invoke<DiffResult>('git_stash_show', { path, index })
+    },
+
+    /**
+     * 列出标签
+     */
+    async listTags(path: string): Promise<TagInfo[]> {
+        return invoke<TagInfo[]>('git_list_tags', { path })
+    },
+
+    /**
+     * 创建标签，提供 message 时创建附注标签
+     */
+    async createTag(
+        path: string,
+        name: string,
+        options: { target?: string; message?: string; force?: boolean } = {},
+    ): Promise<string> {
+        return invoke<string>('git_create_tag', { path, name, ...options })
+    },
+
+    /**
+     * 删除本地标签
+     */
+    async deleteTag(path: string, name: string): Promise<string> {
+        return invoke<string>('git_delete_tag', { path, name })
+    },
+
+    /**
+     * 推送标签到远程
+     */
+    async pushTag(path: string, name: string, remote?: string): Promise<string> {
+        return invoke<string>('git_push_tag', { path, name, remote })
+    },
+
+    /**
+     * 删除远程标签
+     */
+    async deleteRemoteTag(path: string, name: string, remote?: string): Promise<string> {
+        return invoke<string>('git_delete_remote_tag', { path, name, remote })
+    },
+
+    /**
+     * 列出远程仓库
+     */
+    async listRemotes(path: string): Promise<RemoteInfo[]> {
+        return invoke<RemoteInfo[]>('git_list_remotes', { path })
+    },
+
+    /**
+     * 添加远程仓库
+     */
+    async addRemote(path: string, name: string, url: string): Promise<string> {
+        return invoke<string>('git_add_remote', { path, name, url })
+    },
+
+    /**
+     * 删除远程仓库
+     */
+    async removeRemote(path: string, name: string): Promise<string> {
+        return invoke<string>('git_remove_remote', { path, name })
+    },
+
+    /**
+     * 重命名远程仓库
+     */
+    async renameRemote(path: string, oldName: string, newName: string): Promise<string> {
+        return invoke<string>('git_rename_remote', { path, oldName, newName })
+    },
+
+    /**
+     * 修改远程地址，push 为 true 时修改推送地址（空地址表示取消）
+     */
+    async setRemoteUrl(path: string, name: string, url: string, push = false): Promise<string> {
+        return invoke<string>('git_set_remote_url', { path, name, url, push })
+    },
+
+    /**
+     * 拉取远程更新但不合并，未指定远程时拉取全部远程
+     */
+    async fetch(
+        path: string,
+        options: { remote?: string; prune?: boolean; tags?: boolean; operationId?: string } = {},
+    ): Promise<FetchResult> {
+        const { remote, prune = false, tags = false, operationId } = options
+        return invoke<FetchResult>('git_fetch', { path, remote, prune, tags, operationId })
+    },
+
+    /**
+     * 监听后端的 SSH 私钥密码请求
+     */
+    async onSshPassphraseRequest(callback: (request: SshPassphraseRequest) => void): Promise<UnlistenFn> {
+        return listen<SshPassphraseRequest>('ssh-passphrase-request', (event) => callback(event.payload))
+    },
+
+    /**
+     * 回复 SSH 私钥密码请求，passphrase 为 null 表示取消
+     */
+    async submitSshPassphrase(
+        requestId: number,
+        passphrase: string | null,
+        remember = false
+    ): Promise<void> {
+        return invoke('submit_ssh_passphrase', { requestId, passphrase, remember })
     },
 
     /**
-     * 测试 Git 认证
+     * 测试 Git 认证，未指定远程时使用默认远程
      */
-    async testAuth(path: string): Promise<string> {
-        return invoke<string>('test_git_auth', { path })
+    async testAuth(path: string, remote?: string): Promise<string> {
+        return invoke<string>('test_git_auth', { path, remote })
     },
 
     /**
      * Git 克隆
      */
-    async clone(url: string, targetPath: string): Promise<string> {
-        return invoke<string>('git_clone', { url, targetPath })
+    async clone(url: string, targetPath: string, operationId?: string): Promise<string> {
+        return invoke<string>('git_clone', { url, targetPath, operationId })
     },
 }
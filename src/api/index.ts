@@ -2,3 +2,4 @@ export * from './git'
 export * from './svn'
 export * from './config'
 export * from './system'
+export * from './progress'
@@ -0,0 +1,23 @@
+import { invoke } from '@tauri-apps/api/core'
+import { listen, type UnlistenFn } from '@tauri-apps/api/event'
+import type { OperationProgress } from '@/types'
+
+export const progressApi = {
+    /**
+     * 监听克隆、拉取、推送、检出等操作的进度，指定 operationId 时只接收该操作的事件
+     */
+    async listen(callback: (progress: OperationProgress) => void, operationId?: string): Promise<UnlistenFn> {
+        return listen<OperationProgress>('vcs-progress', (event) => {
+            if (!operationId || event.payload.operation_id === operationId) {
+                callback(event.payload)
+            }
+        })
+    },
+
+    /**
+     * 取消正在进行的操作，操作不存在或已结束时返回 false
+     */
+    async cancel(id: string): Promise<boolean> {
+        return invoke<boolean>('cancel_operation', { id })
+    },
+}
@@ -1,60 +1,137 @@
 import { invoke } from '@tauri-apps/api/core'
-import type { SvnStatus } from '@/types'
+import type { CommitDetail, ConflictEntry, ConflictResolution, ConflictVersions, DiffResult, LogOptions, LogPage, SvnBranch, SvnMergeInfo, SvnMergeResult, SvnRemoteCheck, SvnStatus } from '@/types'
 
 export const svnApi = {
     /**
-     * 获取 SVN 仓库状态
+     * 获取 SVN 仓库状态（失败时抛出 SvnError）
      */
     async getStatus(path: string): Promise<SvnStatus> {
         return invoke<SvnStatus>('get_svn_status', { path })
     },
 
     /**
-     * SVN 更新
+     * 检查仓库中的新版本，不修改工作副本（失败时抛出 SvnError）
+     */
+    async checkRemote(path: string): Promise<SvnRemoteCheck> {
+        return invoke<SvnRemoteCheck>('svn_check_remote', { path })
+    },
+
+    /**
+     * SVN 更新（失败时抛出 SvnError）
      */
     async update(path: string): Promise<string> {
         return invoke<string>('svn_update', { path })
     },
 
     /**
-     * SVN 提交
+     * 列出主干、分支和标签（失败时抛出 SvnError）
+     */
+    async listBranches(path: string): Promise<SvnBranch[]> {
+        return invoke<SvnBranch[]>('svn_list_branches', { path })
+    },
+
+    /**
+     * 切换到其他分支或标签，可以是完整 URL、^/ 相对 URL、trunk、branches/x、tags/x 或分支名（失败时抛出 SvnError）
+     */
+    async switch(path: string, urlOrBranch: string): Promise<string> {
+        return invoke<string>('svn_switch', { path, urlOrBranch })
+    },
+
+    /**
+     * 在服务器端复制以创建分支或标签，source 默认为工作副本当前的 URL（失败时抛出 SvnError）
+     */
+    async copy(path: string, target: string, message: string, source?: string): Promise<string> {
+        return invoke<string>('svn_copy', { path, source, target, message })
+    },
+
+    /**
+     * 合并分支到工作副本，revisions 为空时同步合并所有未合并的版本，否则只合并指定版本（失败时抛出 SvnError）
+     */
+    async merge(path: string, sourceUrl: string, revisions?: string[]): Promise<SvnMergeResult> {
+        return invoke<SvnMergeResult>('svn_merge', { path, sourceUrl, revisions })
+    },
+
+    /**
+     * 查询合并源中尚未合并和已合并的版本（失败时抛出 SvnError）
+     */
+    async mergeinfo(path: string, sourceUrl: string): Promise<SvnMergeInfo> {
+        return invoke<SvnMergeInfo>('svn_mergeinfo', { path, sourceUrl })
+    },
+
+    /**
+     * SVN 提交（失败时抛出 SvnError）
      */
     async commit(path: string, message: string): Promise<string> {
         return invoke<string>('svn_commit', { path, message })
     },
 
     /**
-     * SVN Diff
+     * SVN Diff（失败时抛出 SvnError）
      */
-    async diff(path: string): Promise<string> {
-        return invoke<string>('svn_diff', { path })
+    async diff(path: string): Promise<DiffResult> {
+        return invoke<DiffResult>('svn_diff', { path })
     },
 
     /**
-     * 测试 SVN 认证
+     * 测试 SVN 认证（失败时抛出 SvnError）
      */
     async testAuth(path: string): Promise<string> {
         return invoke<string>('test_svn_auth', { path })
     },
 
     /**
-     * SVN 添加文件
+     * SVN 添加文件（失败时抛出 SvnError）
      */
     async add(path: string, files: string[]): Promise<string> {
         return invoke<string>('svn_add', { path, files })
     },
 
     /**
-     * SVN 还原
+     * SVN 还原（失败时抛出 SvnError）
      */
     async revert(path: string, files?: string[]): Promise<string> {
         return invoke<string>('svn_revert', { path, files })
     },
 
     /**
-     * SVN 检出
+     * SVN 检出（失败时抛出 SvnError）
+     */
+    async checkout(url: string, targetPath: string, operationId?: string): Promise<string> {
+        return invoke<string>('svn_checkout', { url, targetPath, operationId })
+    },
+
+    /**
+     * 列出冲突文件（失败时抛出 SvnError）
+     */
+    async listConflicts(path: string): Promise<ConflictEntry[]> {
+        return invoke<ConflictEntry[]>('svn_list_conflicts', { path })
+    },
+
+    /**
+     * 获取冲突文件的 ancestor / ours / theirs 三个版本（失败时抛出 SvnError）
+     */
+    async getConflictVersions(path: string, file: string): Promise<ConflictVersions> {
+        return invoke<ConflictVersions>('svn_get_conflict_versions', { path, file })
+    },
+
+    /**
+     * 解决冲突（svn resolve，失败时抛出 SvnError）
+     */
+    async resolveConflict(path: string, file: string, resolution: ConflictResolution): Promise<string> {
+        return invoke<string>('svn_resolve_conflict', { path, file, resolution })
+    },
+
+    /**
+     * 分页获取提交历史（失败时抛出 SvnError）
+     */
+    async log(path: string, options?: LogOptions): Promise<LogPage> {
+        return invoke<LogPage>('svn_log', { path, options })
+    },
+
+    /**
+     * 获取单个版本的详情和差异（失败时抛出 SvnError）
      */
-    async checkout(url: string, targetPath: string): Promise<string> {
-        return invoke<string>('svn_checkout', { url, targetPath })
+    async showRevision(path: string, rev: string): Promise<CommitDetail> {
+        return invoke<CommitDetail>('svn_show_revision', { path, rev })
     },
 }
@@ -21,6 +21,22 @@ export interface Repository {
     created_at?: string
 }
 
+/**
+ * 文件变更类型
+ */
+export type FileChangeKind = 'added' | 'modified' | 'deleted' | 'renamed' | 'typechange' | 'untracked' | 'ignored'
+
+/**
+ * 单个文件的 Git 状态
+ */
+export interface FileStatus {
+    path: string
+    old_path: string | null
+    index_status: FileChangeKind | null
+    worktree_status: FileChangeKind | null
+    is_conflicted: boolean
+}
+
 /**
  * Git 状态类型定义
  */
@@ -29,9 +45,273 @@ export interface GitStatus {
     commit: string | null
     modified_files: string[]
     untracked_files: string[]
+    files: FileStatus[]
     is_dirty: boolean
     ahead: number | null
     behind: number | null
+    upstream: string | null
+    has_upstream: boolean
+    is_detached: boolean
+}
+
+/**
+ * Diff 行
+ */
+export interface DiffLine {
+    kind: 'context' | 'addition' | 'deletion'
+    old_lineno: number | null
+    new_lineno: number | null
+    content: string
+}
+
+/**
+ * Diff hunk（id 在内容不变时保持稳定）
+ */
+export interface DiffHunk {
+    id: string
+    header: string
+    lines: DiffLine[]
+}
+
+/**
+ * 单个文件的 Diff
+ */
+export interface DiffFile {
+    old_path: string | null
+    new_path: string | null
+    status: 'added' | 'deleted' | 'modified' | 'renamed' | 'copied' | 'typechange'
+    additions: number
+    deletions: number
+    is_binary: boolean
+    hunks: DiffHunk[]
+}
+
+/**
+ * Git / SVN 共用的 Diff 结果
+ */
+export interface DiffResult {
+    files: DiffFile[]
+}
+
+/**
+ * 选中的 hunk，line_indices 为空时表示整个 hunk
+ */
+export interface HunkSelection {
+    hunk_id: string
+    line_indices?: number[] | null
+}
+
+/**
+ * 提交简要信息
+ */
+export interface CommitSummary {
+    id: string
+    summary: string
+    author: string
+    /** Unix 时间戳（秒） */
+    time: number
+}
+
+/**
+ * 历史记录中的一条提交（Git 与 SVN 共用，SVN 的 id 为版本号）
+ */
+export interface LogEntry {
+    id: string
+    parents: string[]
+    author: string
+    author_email: string | null
+    committer: string | null
+    /** Unix 时间戳（秒） */
+    time: number
+    summary: string
+    body: string | null
+    refs: string[]
+}
+
+/**
+ * 分页的历史记录
+ */
+export interface LogPage {
+    entries: LogEntry[]
+    has_more: boolean
+}
+
+/**
+ * 历史记录查询条件
+ */
+export interface LogOptions {
+    skip?: number
+    /** 每页条数，默认 50，小于 1 时按 1 处理 */
+    limit?: number
+    /** Git 为分支/引用或 "a..b" 范围，SVN 为 -r 参数 */
+    revision?: string
+    author?: string
+    /** Unix 时间戳（秒） */
+    since?: number
+    until?: number
+    path?: string
+    message?: string
+}
+
+/**
+ * 提交中变更的文件
+ */
+export interface ChangedPath {
+    path: string
+    status: DiffFile['status']
+}
+
+/**
+ * 相对某个父提交的差异
+ */
+export interface CommitDiff {
+    parent: string | null
+    diff: DiffResult
+}
+
+/**
+ * 提交详情（合并提交对每个父提交各有一项 diff）
+ */
+export interface CommitDetail {
+    commit: LogEntry
+    changed_paths: ChangedPath[]
+    diffs: CommitDiff[]
+}
+
+/**
+ * Git 分支信息
+ */
+export interface BranchInfo {
+    name: string
+    is_remote: boolean
+    is_head: boolean
+    upstream: string | null
+    last_commit: CommitSummary | null
+    ahead: number | null
+    behind: number | null
+}
+
+/**
+ * Git 贮藏条目，index 对应 stash@{index}
+ */
+export interface StashEntry {
+    index: number
+    id: string
+    message: string
+    time: number
+}
+
+/**
+ * Git 标签，附注标签才有 message 和 tagger
+ */
+export interface TagInfo {
+    name: string
+    target: string
+    is_annotated: boolean
+    message: string | null
+    tagger: string | null
+    tagger_email: string | null
+    time: number | null
+}
+
+/**
+ * Git 远程仓库，push_url 为空时推送使用 url
+ */
+export interface RemoteInfo {
+    name: string
+    url: string | null
+    push_url: string | null
+    fetch_refspecs: string[]
+    push_refspecs: string[]
+}
+
+/**
+ * fetch 中变化的引用，新建时 old_id 为空，删除时 new_id 为空
+ */
+export interface RefUpdate {
+    name: string
+    kind: 'created' | 'updated' | 'deleted'
+    old_id: string | null
+    new_id: string | null
+}
+
+/**
+ * fetch 结果
+ */
+export interface FetchResult {
+    remotes: string[]
+    updated_refs: RefUpdate[]
+}
+
+/**
+ * 长时间操作的进度（vcs-progress 事件），current/total 的含义随阶段变化：
+ * 接收阶段为对象数，解析阶段为 delta 数，写入阶段为推送的对象数，检出阶段为文件数
+ */
+export interface OperationProgress {
+    operation_id: string
+    operation: 'clone' | 'fetch' | 'pull' | 'push' | 'checkout'
+    phase: 'counting' | 'compressing' | 'receiving' | 'resolving' | 'writing' | 'checkout'
+    current: number
+    /** 总数未知时为 0 */
+    total: number
+    bytes: number
+    message: string | null
+}
+
+/**
+ * 后端请求输入 SSH 私钥密码（ssh-passphrase-request 事件）
+ */
+export interface SshPassphraseRequest {
+    request_id: number
+    key_path: string
+}
+
+/**
+ * 冲突条目（Git 中为索引里三个版本的 blob id，SVN 中为空）
+ */
+export interface ConflictEntry {
+    path: string
+    ancestor_id: string | null
+    our_id: string | null
+    their_id: string | null
+}
+
+/**
+ * 冲突文件的三个版本内容
+ */
+export interface ConflictVersions {
+    path: string
+    ancestor: string | null
+    ours: string | null
+    theirs: string | null
+    is_binary: boolean
+}
+
+/**
+ * 冲突解决方式
+ */
+export type ConflictResolution =
+    | { type: 'ours' }
+    | { type: 'theirs' }
+    | { type: 'content'; content: string }
+
+/**
+ * Git 操作的结构化错误
+ */
+export interface GitError {
+    kind: 'dirty_worktree' | 'conflict' | 'cancelled' | 'other'
+    message: string
+    conflicts: string[]
+}
+
+/**
+ * SVN 操作的结构化错误
+ * auth: 用户名或密码错误、缺少凭据或没有访问权限
+ * certificate: 服务器证书不受信任
+ */
+export interface SvnError {
+    kind: 'auth' | 'certificate' | 'cancelled' | 'other'
+    message: string
 }
 
 /**
@@ -41,11 +321,151 @@ export interface SvnStatus {
     revision: string | null
     url: string | null
     repository_root?: string | null
+    /** 相对于工作副本根目录的路径 */
     modified_files: string[]
     untracked_files: string[]
+    /** 有文本、属性或树冲突的文件 */
+    conflicted_files: string[]
+    /** 在本工作副本中持有仓库锁的文件 */
+    locked_files: string[]
+    /** 工作副本被锁定（操作中断），需要执行 svn cleanup */
+    needs_cleanup: boolean
+    /** 所有有状态的文件 */
+    files: SvnFileStatus[]
     is_dirty: boolean
     author: string | null
+    /** 最后修改时间（ISO 8601 UTC 格式） */
+    date: string | null
+    /** 最后修改时间的 Unix 时间戳 */
+    time: number | null
+    last_changed_revision: string | null
+    /** 相对于仓库根的 URL，例如 ^/trunk */
+    relative_url: string | null
+    repository_uuid: string | null
+    working_copy_root: string | null
+    /** 落后仓库的版本数，未检查过更新时为 null */
+    behind: number | null
+}
+
+/**
+ * svn status 第 1 列的内容状态
+ */
+export type SvnItemStatus =
+    | 'normal'
+    | 'added'
+    | 'conflicted'
+    | 'deleted'
+    | 'ignored'
+    | 'modified'
+    | 'replaced'
+    | 'external'
+    | 'unversioned'
+    | 'missing'
+    | 'incomplete'
+    | 'obstructed'
+
+/**
+ * 仓库锁信息
+ */
+export interface SvnLock {
+    token: string | null
+    owner: string | null
+    comment: string | null
+    created: string | null
+}
+
+/**
+ * SVN 工作副本中单个文件的状态，对应 svn status 输出的各列
+ */
+export interface SvnFileStatus {
+    path: string
+    item: SvnItemStatus
+    props: 'normal' | 'modified' | 'conflicted'
+    /** 工作副本目录被锁定（L） */
+    wc_locked: boolean
+    /** 带历史记录的添加（+） */
+    copied: boolean
+    /** 已切换到其他 URL（S） */
+    switched: boolean
+    /** 文件外部定义（X） */
+    file_external: boolean
+    /** 本工作副本持有的仓库锁（K） */
+    lock: SvnLock | null
+    /** 树冲突（C） */
+    tree_conflicted: boolean
+    moved_from: string | null
+    moved_to: string | null
+    changelist: string | null
+    revision: string | null
+    /** 仓库中的状态，仅在检查更新（svn status -u）时返回 */
+    repos: SvnReposStatus | null
+}
+
+/**
+ * 文件在仓库中的状态（svn status -u 的 repos-status）
+ */
+export interface SvnReposStatus {
+    item: SvnItemStatus
+    props: 'normal' | 'modified' | 'conflicted'
+    /** 仓库中的锁，与本地 lock 不同时表示被他人锁定或已失效 */
+    lock: SvnLock | null
+}
+
+/**
+ * SVN 标准目录结构中的主干、分支或标签
+ */
+export interface SvnBranch {
+    /** 分支或标签名，主干为 trunk */
+    name: string
+    kind: 'trunk' | 'branch' | 'tag'
+    url: string
+    /** 相对于仓库根的 URL，例如 ^/branches/feature */
+    relative_url: string
+    /** 最后修改的版本、作者和时间 */
+    revision: string | null
+    author: string | null
     date: string | null
+    /** 工作副本当前是否位于此分支 */
+    is_current: boolean
+}
+
+/**
+ * 合并后的工作副本状态，files 与 SvnStatus.files 相同
+ */
+export interface SvnMergeResult {
+    message: string
+    files: SvnFileStatus[]
+    /** 有冲突的文件，可以用 svnApi.resolveConflict 逐个解决 */
+    conflicted_files: string[]
+}
+
+/**
+ * 合并源相对于工作副本的合并情况
+ */
+export interface SvnMergeInfo {
+    source_url: string
+    /** 尚未合并的版本，从新到旧 */
+    eligible: string[]
+    /** 已合并的版本，从新到旧 */
+    merged: string[]
+    /** 最近 50 个尚未合并版本的日志 */
+    eligible_log: LogEntry[]
+}
+
+/**
+ * 检查更新的结果
+ */
+export interface SvnRemoteCheck {
+    /** 仓库的最新版本 */
+    head_revision: string | null
+    /** 工作副本根目录的版本 */
+    base_revision: string | null
+    /** 更新时会变化的文件 */
+    incoming_files: SvnFileStatus[]
+    /** 本地已修改且仓库中也有变化的文件，更新时可能产生冲突 */
+    conflicting_files: string[]
+    /** 落后的版本数 */
+    behind: number
 }
 
 /**
@@ -56,15 +476,52 @@ export interface GitConfig {
     ssh_key_path: string | null
     auto_fetch: boolean
     auto_push: boolean
+    pull_strategy: PullStrategy | null
+    /** 按主机名配置的 HTTPS 访问令牌 */
+    tokens: Record<string, GitToken>
 }
 
+/**
+ * 单个主机的 HTTPS 访问令牌
+ */
+export interface GitToken {
+    /** 为空时使用 URL 中的用户名，都没有时使用 oauth2 */
+    username: string | null
+    /** 仅用于提交新令牌，保存后存入系统密钥库，读取配置时不会返回 */
+    token?: string | null
+    /** 令牌在系统密钥库中的名称 */
+    token_ref: string | null
+}
+
+/**
+ * 拉取策略
+ */
+export type PullStrategy = 'merge' | 'rebase'
+
 /**
  * SVN 配置
  */
 export interface SvnConfig {
     auto_update: boolean
     username: string | null
-    password: string | null
+    /** 仅用于提交新密码，保存后存入系统密钥库，读取配置时不会返回 */
+    password?: string | null
+    /** 密码在系统密钥库中的名称，为 null 表示未保存密码 */
+    password_ref: string | null
+    /** 信任无法验证的服务器证书（自签名、域名不匹配、已过期等） */
+    trust_server_cert: boolean
+    /** 按工作副本路径单独配置的凭据，优先于全局用户名和密码 */
+    repositories: Record<string, SvnCredentials>
+}
+
+/**
+ * 单个 SVN 工作副本的凭据
+ */
+export interface SvnCredentials {
+    username: string | null
+    /** 仅用于提交新密码，读取配置时不会返回 */
+    password?: string | null
+    password_ref: string | null
 }
 
 /**
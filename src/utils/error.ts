@@ -0,0 +1,13 @@
+/**
+ * 从命令返回的错误中提取可读的消息
+ * 后端错误可能是字符串，也可能是带 message 字段的结构化错误（如 GitError）
+ */
+export function errorMessage(error: unknown): string {
+    if (typeof error === 'string') {
+        return error
+    }
+    if (error && typeof error === 'object' && 'message' in error) {
+        return String((error as { message: unknown }).message)
+    }
+    return String(error)
+}
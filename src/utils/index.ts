@@ -1,3 +1,4 @@
 export * from './date'
 export * from './debounce'
 export * from './eventBus'
+export * from './error'